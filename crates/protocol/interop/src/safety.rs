@@ -1,6 +1,9 @@
 //! Message safety level for interoperability.
-use alloc::string::{String, ToString};
-use core::str::FromStr;
+use alloc::{
+    collections::BTreeMap,
+    string::{String, ToString},
+};
+use core::{cmp::Ordering, str::FromStr};
 use derive_more::Display;
 use thiserror::Error;
 /// The safety level of a message.
@@ -22,10 +25,228 @@ pub enum SafetyLevel {
     Invalid,
 }
 
+impl SafetyLevel {
+    /// The rank of this level on the monotone interop safety scale, used for total ordering.
+    ///
+    /// Stronger guarantees rank higher: `Finalized` is the strongest and `Unsafe` the weakest.
+    /// [`SafetyLevel::Invalid`] is not a point on this scale; it is assigned the lowest rank so
+    /// that it sorts below every real level under [`Ord`], but see [`SafetyLevel::partial_cmp`]
+    /// for how comparisons involving `Invalid` are deliberately left undefined.
+    const fn rank(self) -> u8 {
+        match self {
+            Self::Invalid => 0,
+            Self::Unsafe => 1,
+            Self::CrossUnsafe => 2,
+            Self::LocalSafe => 3,
+            Self::Safe => 4,
+            Self::Finalized => 5,
+        }
+    }
+
+    /// Returns `true` if this level is at least as strong as `minimum` on the safety scale.
+    ///
+    /// [`SafetyLevel::Invalid`] is never on the scale, so `meets` returns `false` whenever either
+    /// `self` or `minimum` is `Invalid`, regardless of the threshold.
+    pub fn meets(self, minimum: SafetyLevel) -> bool {
+        if matches!(self, Self::Invalid) || matches!(minimum, Self::Invalid) {
+            return false;
+        }
+        self >= minimum
+    }
+
+    /// Returns the weaker of the two safety levels.
+    ///
+    /// This uses the total [`Ord`] ordering, so [`SafetyLevel::Invalid`] (the lowest element) is
+    /// returned whenever either argument is `Invalid`.
+    pub fn min_of(self, other: SafetyLevel) -> SafetyLevel {
+        core::cmp::min_by(self, other, Ord::cmp)
+    }
+
+    /// The canonical kebab-case spelling of this level.
+    ///
+    /// This is the spelling guaranteed to round-trip through [`from_str`](FromStr::from_str), and
+    /// the one to use for stable wire formats.
+    pub const fn as_canonical_str(self) -> &'static str {
+        match self {
+            Self::Finalized => "finalized",
+            Self::Safe => "safe",
+            Self::LocalSafe => "local-safe",
+            Self::CrossUnsafe => "cross-unsafe",
+            Self::Unsafe => "unsafe",
+            Self::Invalid => "invalid",
+        }
+    }
+
+    /// The safety levels this level may legally transition to.
+    ///
+    /// The lifecycle promotes a message one rung up the ladder at a time
+    /// (`Unsafe → CrossUnsafe → LocalSafe → Safe → Finalized`), and every non-terminal level may
+    /// additionally move to [`SafetyLevel::Invalid`]. `Invalid` is terminal and has no successors.
+    /// The edges are listed explicitly rather than derived from [`rank`](Self::rank) so the legal
+    /// moves stay auditable.
+    const fn allowed_transitions(self) -> &'static [SafetyLevel] {
+        match self {
+            Self::Unsafe => &[Self::CrossUnsafe, Self::Invalid],
+            Self::CrossUnsafe => &[Self::LocalSafe, Self::Invalid],
+            Self::LocalSafe => &[Self::Safe, Self::Invalid],
+            Self::Safe => &[Self::Finalized, Self::Invalid],
+            Self::Finalized => &[Self::Invalid],
+            Self::Invalid => &[],
+        }
+    }
+
+    /// Returns `true` if a direct transition from `self` to `target` is legal.
+    pub fn can_transition_to(self, target: SafetyLevel) -> bool {
+        self.allowed_transitions().contains(&target)
+    }
+
+    /// Promotes this level to `target`, validating the move against the legal lifecycle.
+    ///
+    /// Returns the [`SafetyTransition`] that was taken, or an [`IllegalTransition`] error if the
+    /// move is not one of the [`allowed_transitions`](Self::allowed_transitions) — for example a
+    /// backward step such as `Finalized → Unsafe`, or any move out of the terminal `Invalid`
+    /// state.
+    pub fn promote_to(self, target: SafetyLevel) -> Result<SafetyTransition, IllegalTransition> {
+        if self.can_transition_to(target) {
+            Ok(SafetyTransition { from: self, to: target })
+        } else {
+            Err(IllegalTransition { from: self, to: target })
+        }
+    }
+}
+
+/// A validated, legal transition between two [`SafetyLevel`]s.
+///
+/// Returned by [`SafetyLevel::promote_to`] only for a move from [`from`](Self::from) to
+/// [`to`](Self::to) that the safety lifecycle permits. Its fields are public, so a value built
+/// directly is not checked against the lifecycle.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SafetyTransition {
+    /// The level the message was promoted from.
+    pub from: SafetyLevel,
+    /// The level the message was promoted to.
+    pub to: SafetyLevel,
+}
+
+/// Error returned by [`SafetyLevel::promote_to`] when a transition is not permitted.
+#[derive(Error, Debug, Clone, Copy, PartialEq, Eq)]
+#[error("illegal safety transition from {from} to {to}")]
+pub struct IllegalTransition {
+    /// The level the transition was attempted from.
+    pub from: SafetyLevel,
+    /// The level the transition was attempted to.
+    pub to: SafetyLevel,
+}
+
+impl Ord for SafetyLevel {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.rank().cmp(&other.rank())
+    }
+}
+
+impl PartialOrd for SafetyLevel {
+    /// Compares two safety levels on the monotone safety scale.
+    ///
+    /// Returns `None` when either side is [`SafetyLevel::Invalid`], because `Invalid` is not a
+    /// point on the scale and cannot be meaningfully ordered against a real level. Note the
+    /// deliberate asymmetry with [`Ord`]: the total order ([`Ord::cmp`]) treats `Invalid` as the
+    /// lowest element, whereas `partial_cmp` refuses to order it at all. The `<`, `<=`, `>` and
+    /// `>=` operators go through `partial_cmp`, and so does `slice::sort`, which compares with
+    /// `<` and therefore does not move `Invalid` to the front; `sort_by(Ord::cmp)` places it first.
+    #[allow(clippy::non_canonical_partial_ord_impl)]
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        if matches!(self, Self::Invalid) || matches!(other, Self::Invalid) {
+            return None;
+        }
+        Some(self.cmp(other))
+    }
+}
+
+/// A per-chain interop safety policy.
+///
+/// Maps a chain ID to the minimum [`SafetyLevel`] a message from that chain must [`meet`] before it
+/// is accepted, with an optional wildcard default applied to any chain not named explicitly.
+///
+/// Policies are typically parsed from a `RUST_LOG`-style directive string (see
+/// [`SafetyPolicy::from_str`]) so operators can configure heterogeneous trust requirements from a
+/// single env-var or CLI flag.
+///
+/// [`meet`]: SafetyLevel::meets
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct SafetyPolicy {
+    per_chain: BTreeMap<u64, SafetyLevel>,
+    default: Option<SafetyLevel>,
+}
+
+impl SafetyPolicy {
+    /// The minimum safety level required for `chain_id`.
+    ///
+    /// Falls back to the wildcard default when the chain is not named explicitly. When no default
+    /// was configured either, this returns [`SafetyLevel::Finalized`] — the strongest level — so an
+    /// unconfigured chain fails closed rather than silently accepting weak messages.
+    pub fn required_for(&self, chain_id: u64) -> SafetyLevel {
+        self.per_chain
+            .get(&chain_id)
+            .copied()
+            .or(self.default)
+            .unwrap_or(SafetyLevel::Finalized)
+    }
+
+    /// Returns `true` if `observed` satisfies the policy's requirement for `chain_id`.
+    pub fn is_satisfied(&self, chain_id: u64, observed: SafetyLevel) -> bool {
+        observed.meets(self.required_for(chain_id))
+    }
+}
+
+impl FromStr for SafetyPolicy {
+    type Err = SafetyPolicyParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut policy = SafetyPolicy::default();
+        for entry in s.split(',').map(str::trim).filter(|e| !e.is_empty()) {
+            let (target, level) = entry
+                .split_once('=')
+                .ok_or_else(|| SafetyPolicyParseError::MalformedPair(entry.to_string()))?;
+            let level = SafetyLevel::from_str(level.trim())
+                .map_err(SafetyPolicyParseError::InvalidLevel)?;
+            match target.trim() {
+                "*" => policy.default = Some(level),
+                chain => {
+                    let chain_id = chain
+                        .parse::<u64>()
+                        .map_err(|_| SafetyPolicyParseError::UnknownChain(chain.to_string()))?;
+                    policy.per_chain.insert(chain_id, level);
+                }
+            }
+        }
+        Ok(policy)
+    }
+}
+
+/// Error when parsing a [`SafetyPolicy`] directive string.
+#[derive(Error, Debug, Clone, PartialEq, Eq)]
+pub enum SafetyPolicyParseError {
+    /// A target token was neither a `u64` chain ID nor the `*` wildcard.
+    #[error("unknown chain target: {0}")]
+    UnknownChain(String),
+    /// An entry was missing the `target=level` separator.
+    #[error("malformed policy entry, expected `target=level`: {0}")]
+    MalformedPair(String),
+    /// The level component of an entry was not a valid [`SafetyLevel`].
+    #[error(transparent)]
+    InvalidLevel(#[from] SafetyLevelParseError),
+}
+
 impl FromStr for SafetyLevel {
     type Err = SafetyLevelParseError;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
+        if s.is_empty() {
+            return Err(SafetyLevelParseError::EmptyInput);
+        }
+        if s.trim() != s {
+            return Err(SafetyLevelParseError::TrailingWhitespace { input: s.to_string() });
+        }
         match s.to_lowercase().as_str() {
             "finalized" => Ok(Self::Finalized),
             "safe" => Ok(Self::Safe),
@@ -33,15 +254,43 @@ impl FromStr for SafetyLevel {
             "cross-unsafe" | "crossunsafe" => Ok(Self::CrossUnsafe),
             "unsafe" => Ok(Self::Unsafe),
             "invalid" => Ok(Self::Invalid),
-            _ => Err(SafetyLevelParseError(s.to_string())),
+            _ => Err(SafetyLevelParseError::UnknownVariant {
+                input: s.to_string(),
+                expected: SafetyLevel::CANONICAL_VARIANTS,
+            }),
         }
     }
 }
 
-/// Error when parsing SafetyLevel from string.
-#[derive(Error, Debug)]
-#[error("Invalid SafetyLevel, error: {0}")]
-pub struct SafetyLevelParseError(pub String);
+impl SafetyLevel {
+    /// The canonical kebab-case spellings of every variant, in strength order.
+    ///
+    /// Surfaced in [`SafetyLevelParseError::UnknownVariant`] as the "did you mean" list.
+    const CANONICAL_VARIANTS: &'static [&'static str] =
+        &["finalized", "safe", "local-safe", "cross-unsafe", "unsafe", "invalid"];
+}
+
+/// Error when parsing a [`SafetyLevel`] from a string.
+#[derive(Error, Debug, Clone, PartialEq, Eq)]
+pub enum SafetyLevelParseError {
+    /// The input was empty.
+    #[error("empty safety level")]
+    EmptyInput,
+    /// The input carried leading or trailing whitespace.
+    #[error("safety level has surrounding whitespace: {input:?}")]
+    TrailingWhitespace {
+        /// The offending input, verbatim.
+        input: String,
+    },
+    /// The input did not match any known variant.
+    #[error("unknown safety level {input:?}, expected one of {expected:?}")]
+    UnknownVariant {
+        /// The unrecognized input.
+        input: String,
+        /// The canonical spellings the caller may have meant.
+        expected: &'static [&'static str],
+    },
+}
 
 #[cfg(test)]
 #[cfg(feature = "serde")]
@@ -85,3 +334,160 @@ fn test_safety_level_from_str_invalid() {
     assert!(SafetyLevel::from_str("").is_err());
     assert!(SafetyLevel::from_str("safe ").is_err());
 }
+
+#[test]
+fn test_safety_level_ordering() {
+    assert!(SafetyLevel::Finalized > SafetyLevel::Safe);
+    assert!(SafetyLevel::Safe > SafetyLevel::LocalSafe);
+    assert!(SafetyLevel::LocalSafe > SafetyLevel::CrossUnsafe);
+    assert!(SafetyLevel::CrossUnsafe > SafetyLevel::Unsafe);
+}
+
+#[test]
+fn test_safety_level_invalid_is_unordered() {
+    assert_eq!(SafetyLevel::Invalid.partial_cmp(&SafetyLevel::Unsafe), None);
+    assert_eq!(SafetyLevel::Safe.partial_cmp(&SafetyLevel::Invalid), None);
+    assert_eq!(SafetyLevel::Invalid.partial_cmp(&SafetyLevel::Invalid), None);
+}
+
+#[test]
+fn test_safety_level_invalid_sorts_lowest() {
+    let mut levels = [
+        SafetyLevel::Safe,
+        SafetyLevel::Invalid,
+        SafetyLevel::Finalized,
+        SafetyLevel::Unsafe,
+    ];
+    levels.sort_by(Ord::cmp);
+    assert_eq!(
+        levels,
+        [
+            SafetyLevel::Invalid,
+            SafetyLevel::Unsafe,
+            SafetyLevel::Safe,
+            SafetyLevel::Finalized,
+        ]
+    );
+}
+
+#[test]
+fn test_safety_level_meets() {
+    assert!(SafetyLevel::Safe.meets(SafetyLevel::CrossUnsafe));
+    assert!(SafetyLevel::CrossUnsafe.meets(SafetyLevel::CrossUnsafe));
+    assert!(!SafetyLevel::Unsafe.meets(SafetyLevel::CrossUnsafe));
+    // `Invalid` never meets any threshold, and is never a valid threshold.
+    assert!(!SafetyLevel::Invalid.meets(SafetyLevel::Unsafe));
+    assert!(!SafetyLevel::Finalized.meets(SafetyLevel::Invalid));
+}
+
+#[test]
+fn test_safety_level_min_of() {
+    assert_eq!(SafetyLevel::Safe.min_of(SafetyLevel::Finalized), SafetyLevel::Safe);
+    assert_eq!(SafetyLevel::Unsafe.min_of(SafetyLevel::Safe), SafetyLevel::Unsafe);
+    assert_eq!(SafetyLevel::Safe.min_of(SafetyLevel::Invalid), SafetyLevel::Invalid);
+}
+
+#[test]
+fn test_safety_level_forward_promotion() {
+    assert_eq!(
+        SafetyLevel::Unsafe.promote_to(SafetyLevel::CrossUnsafe).unwrap(),
+        SafetyTransition { from: SafetyLevel::Unsafe, to: SafetyLevel::CrossUnsafe }
+    );
+    assert!(SafetyLevel::CrossUnsafe.can_transition_to(SafetyLevel::LocalSafe));
+    assert!(SafetyLevel::LocalSafe.can_transition_to(SafetyLevel::Safe));
+    assert!(SafetyLevel::Safe.can_transition_to(SafetyLevel::Finalized));
+}
+
+#[test]
+fn test_safety_level_invalidation_edge() {
+    for level in
+        [SafetyLevel::Unsafe, SafetyLevel::CrossUnsafe, SafetyLevel::LocalSafe, SafetyLevel::Safe]
+    {
+        assert!(level.can_transition_to(SafetyLevel::Invalid));
+    }
+    // `Finalized` may still be invalidated, but `Invalid` itself is terminal.
+    assert!(SafetyLevel::Finalized.can_transition_to(SafetyLevel::Invalid));
+    assert!(!SafetyLevel::Invalid.can_transition_to(SafetyLevel::Unsafe));
+}
+
+#[test]
+fn test_safety_level_canonical_round_trip() {
+    for level in [
+        SafetyLevel::Finalized,
+        SafetyLevel::Safe,
+        SafetyLevel::LocalSafe,
+        SafetyLevel::CrossUnsafe,
+        SafetyLevel::Unsafe,
+        SafetyLevel::Invalid,
+    ] {
+        assert_eq!(SafetyLevel::from_str(level.as_canonical_str()), Ok(level));
+    }
+}
+
+#[test]
+fn test_safety_level_parse_error_kinds() {
+    assert_eq!(SafetyLevel::from_str(""), Err(SafetyLevelParseError::EmptyInput));
+    assert_eq!(
+        SafetyLevel::from_str("safe "),
+        Err(SafetyLevelParseError::TrailingWhitespace { input: "safe ".to_string() })
+    );
+    assert_eq!(
+        SafetyLevel::from_str("bogus"),
+        Err(SafetyLevelParseError::UnknownVariant {
+            input: "bogus".to_string(),
+            expected: SafetyLevel::CANONICAL_VARIANTS,
+        })
+    );
+}
+
+#[test]
+fn test_safety_policy_from_str() {
+    let policy = SafetyPolicy::from_str("10=finalized,8453=safe,*=cross-unsafe").unwrap();
+    assert_eq!(policy.required_for(10), SafetyLevel::Finalized);
+    assert_eq!(policy.required_for(8453), SafetyLevel::Safe);
+    // Unlisted chain falls back to the wildcard default.
+    assert_eq!(policy.required_for(1), SafetyLevel::CrossUnsafe);
+}
+
+#[test]
+fn test_safety_policy_fails_closed_without_default() {
+    let policy = SafetyPolicy::from_str("10=safe").unwrap();
+    assert_eq!(policy.required_for(999), SafetyLevel::Finalized);
+}
+
+#[test]
+fn test_safety_policy_is_satisfied() {
+    let policy = SafetyPolicy::from_str("10=cross-unsafe").unwrap();
+    assert!(policy.is_satisfied(10, SafetyLevel::Safe));
+    assert!(policy.is_satisfied(10, SafetyLevel::CrossUnsafe));
+    assert!(!policy.is_satisfied(10, SafetyLevel::Unsafe));
+    assert!(!policy.is_satisfied(10, SafetyLevel::Invalid));
+}
+
+#[test]
+fn test_safety_policy_parse_errors() {
+    assert_eq!(
+        SafetyPolicy::from_str("10"),
+        Err(SafetyPolicyParseError::MalformedPair("10".to_string()))
+    );
+    assert_eq!(
+        SafetyPolicy::from_str("abc=safe"),
+        Err(SafetyPolicyParseError::UnknownChain("abc".to_string()))
+    );
+    assert!(matches!(
+        SafetyPolicy::from_str("10=bogus"),
+        Err(SafetyPolicyParseError::InvalidLevel(_))
+    ));
+}
+
+#[test]
+fn test_safety_level_illegal_transitions() {
+    assert_eq!(
+        SafetyLevel::Finalized.promote_to(SafetyLevel::Unsafe),
+        Err(IllegalTransition { from: SafetyLevel::Finalized, to: SafetyLevel::Unsafe })
+    );
+    // Skipping a rung forward is not a single legal step.
+    assert!(SafetyLevel::Unsafe.promote_to(SafetyLevel::Safe).is_err());
+    // A no-op self transition is not an edge in the table.
+    assert!(SafetyLevel::Safe.promote_to(SafetyLevel::Safe).is_err());
+}